@@ -1,7 +1,15 @@
-use anyhow::Result;
-use std::{sync::mpsc, thread, time::Duration};
+use anyhow::{anyhow, Result};
+use crossbeam_channel::{bounded, select, Receiver, Sender};
+use std::{thread, time::Duration};
 
 const NUM_PRODUCERS: usize = 4;
+// Bounded queue capacity. Once this many messages are buffered, `producer`'s
+// `send` blocks until `consumer` drains one (real backpressure), rather than
+// letting an unbounded channel grow without limit when the consumer stalls.
+const CHANNEL_CAPACITY: usize = 16;
+// How long `consumer` waits for a message before it assumes producers are
+// stalled and logs a warning.
+const IDLE_TIMEOUT: Duration = Duration::from_secs(2);
 
 #[derive(Debug)]
 struct Msg {
@@ -15,7 +23,8 @@ impl Msg {
 }
 
 fn main() -> Result<()> {
-    let (tx, rx) = mpsc::channel();
+    let (tx, rx) = bounded(CHANNEL_CAPACITY);
+    let (shutdown_tx, shutdown_rx) = bounded(0);
 
     // 创建 producer 线程
     for i in 0..NUM_PRODUCERS {
@@ -25,18 +34,25 @@ fn main() -> Result<()> {
     drop(tx); //释放tx, 否则rx无法结束
 
     // 创建 consumer 线程
-    let consumer = thread::spawn(move || consumer(rx));
+    let consumer = thread::spawn(move || consumer(rx, shutdown_rx, IDLE_TIMEOUT));
+
+    // Demo: ask the consumer to shut down gracefully after a few seconds
+    // instead of only stopping once every producer's Sender drops.
+    thread::sleep(Duration::from_secs(3));
+    let _ = shutdown_tx.send(());
 
     let secret = consumer.join().unwrap()?;
     println!("consumer: secret:{}", secret);
-    // thread::sleep(Duration::from_secs(5));
     Ok(())
 }
 
-fn producer(idx: usize, tx: mpsc::Sender<Msg>) -> Result<()> {
+fn producer(idx: usize, tx: Sender<Msg>) -> Result<()> {
     loop {
         let value = rand::random::<usize>();
-        tx.send(Msg::new(idx, value))?;
+        // Blocks here once the bounded queue is full; a distinct error
+        // (rather than the raw SendError) surfaces if consumer has exited.
+        tx.send(Msg::new(idx, value))
+            .map_err(|_| anyhow!("producer: idx:{}, all consumers have exited", idx))?;
         let sleep_time = rand::random::<u64>() % 1000;
         thread::sleep(Duration::from_millis(sleep_time));
         //random exit the producer
@@ -48,10 +64,30 @@ fn producer(idx: usize, tx: mpsc::Sender<Msg>) -> Result<()> {
     Ok(())
 }
 
-fn consumer(rx: mpsc::Receiver<Msg>) -> Result<usize> {
-    for msg in rx {
-        println!("consumer: idx:{}, value:{}", msg.idx, msg.value);
+/// Waits on whichever of {data message, shutdown signal, idle timeout} fires
+/// first instead of only draining `rx` until every producer drops its
+/// `Sender`. A shutdown signal flushes whatever is still buffered and
+/// returns; an idle timeout just logs a warning and keeps waiting.
+fn consumer(rx: Receiver<Msg>, shutdown_rx: Receiver<()>, idle_timeout: Duration) -> Result<usize> {
+    loop {
+        select! {
+            recv(rx) -> msg => match msg {
+                Ok(msg) => println!("consumer: idx:{}, value:{}", msg.idx, msg.value),
+                Err(_) => {
+                    println!("consumer: exit");
+                    return Ok(42);
+                }
+            },
+            recv(shutdown_rx) -> _ => {
+                while let Ok(msg) = rx.try_recv() {
+                    println!("consumer: idx:{}, value:{}", msg.idx, msg.value);
+                }
+                println!("consumer: shutdown signal received, exit");
+                return Ok(42);
+            },
+            default(idle_timeout) => {
+                println!("consumer: producers idle for {:?}", idle_timeout);
+            }
+        }
     }
-    println!("consumer: exit");
-    Ok(42)
 }
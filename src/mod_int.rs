@@ -0,0 +1,82 @@
+use std::{
+    fmt,
+    ops::{Add, AddAssign, Mul},
+};
+
+// An integer that reduces every arithmetic operation modulo `P`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Mod<const P: u64>(u64);
+
+impl<const P: u64> From<u64> for Mod<P> {
+    fn from(value: u64) -> Self {
+        Mod(value % P)
+    }
+}
+
+impl<const P: u64> From<Mod<P>> for u64 {
+    fn from(value: Mod<P>) -> Self {
+        value.0
+    }
+}
+
+impl<const P: u64> Add for Mod<P> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Mod(((self.0 as u128 + rhs.0 as u128) % P as u128) as u64)
+    }
+}
+
+impl<const P: u64> AddAssign for Mod<P> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const P: u64> Mul for Mod<P> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Mod((self.0 as u128 * rhs.0 as u128 % P as u128) as u64)
+    }
+}
+
+impl<const P: u64> fmt::Display for Mod<P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MOD: u64 = 1_000_000_007;
+
+    #[test]
+    fn test_add_wraps_around_p() {
+        let a: Mod<MOD> = (MOD - 1).into();
+        let b: Mod<MOD> = 2.into();
+        assert_eq!(u64::from(a + b), 1);
+    }
+
+    #[test]
+    fn test_mul_reduces_modulo_p() {
+        let a: Mod<MOD> = (MOD - 1).into();
+        let b: Mod<MOD> = (MOD - 1).into();
+        assert_eq!(u64::from(a * b), 1);
+    }
+
+    #[test]
+    fn test_display_prints_canonical_residue() {
+        let a: Mod<MOD> = (MOD + 5).into();
+        assert_eq!(format!("{}", a), "5");
+    }
+
+    #[test]
+    fn test_add_does_not_overflow_near_u64_max() {
+        let a: Mod<{ u64::MAX }> = (u64::MAX - 1).into();
+        let b: Mod<{ u64::MAX }> = (u64::MAX - 1).into();
+        assert_eq!(u64::from(a + b), u64::MAX - 2);
+    }
+}